@@ -0,0 +1,156 @@
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tauri::{Manager, State, Window};
+
+use crate::sidecar::{self, SidecarState};
+
+/// Shared cancellation flag for the in-flight analysis run, checked between
+/// messages so a long mailbox can be aborted without tearing down the window.
+#[derive(Default)]
+pub struct AnalysisState {
+    cancelled: Arc<AtomicBool>,
+    running: Arc<AtomicBool>,
+}
+
+#[derive(Clone, Serialize)]
+struct AnalysisProgress {
+    processed: usize,
+    total: usize,
+    subject: String,
+    sender: String,
+}
+
+#[derive(Clone, Serialize)]
+struct AnalysisComplete {
+    results: Vec<String>,
+}
+
+#[derive(Clone, Serialize)]
+struct AnalysisError {
+    message: String,
+}
+
+struct ParsedMessage {
+    subject: String,
+    sender: String,
+    body: String,
+}
+
+/// Splits a raw mailbox blob into individual messages on blank lines and
+/// pulls out the headers we report progress on.
+///
+/// This is a placeholder splitter good enough to drive the event pipeline;
+/// real `.eml`/`.mbox` parsing lives with the file-loading commands.
+fn split_messages(email_data: &str) -> Vec<ParsedMessage> {
+    email_data
+        .split("\n\n")
+        .filter(|chunk| !chunk.trim().is_empty())
+        .map(|chunk| {
+            let subject = chunk
+                .lines()
+                .find_map(|line| line.strip_prefix("Subject:"))
+                .unwrap_or("(no subject)")
+                .trim()
+                .to_string();
+            let sender = chunk
+                .lines()
+                .find_map(|line| line.strip_prefix("From:"))
+                .unwrap_or("(unknown sender)")
+                .trim()
+                .to_string();
+            ParsedMessage {
+                subject,
+                sender,
+                body: chunk.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Starts an analysis run and returns immediately; progress is reported
+/// entirely through `analysis-progress`, `analysis-complete` and
+/// `analysis-error` events so the frontend can drive a live progress bar
+/// instead of blocking on one opaque call. Rejects a second call while a run
+/// is already in flight rather than letting two runs share one cancellation
+/// flag and race each other's events.
+#[tauri::command]
+pub async fn analyze_email(
+    window: Window,
+    state: State<'_, AnalysisState>,
+    sidecar_state: State<'_, Arc<SidecarState>>,
+    email_data: String,
+) -> Result<(), String> {
+    if state.running.swap(true, Ordering::SeqCst) {
+        return Err("an analysis is already in progress".to_string());
+    }
+
+    state.cancelled.store(false, Ordering::SeqCst);
+    let cancelled = state.cancelled.clone();
+    let running = state.running.clone();
+    let sidecar_state = sidecar_state.inner().clone();
+
+    tauri::async_runtime::spawn(async move {
+        run_analysis(&window, &cancelled, &sidecar_state, &email_data).await;
+        running.store(false, Ordering::SeqCst);
+    });
+
+    Ok(())
+}
+
+async fn run_analysis(
+    window: &Window,
+    cancelled: &AtomicBool,
+    sidecar_state: &SidecarState,
+    email_data: &str,
+) {
+    let messages = split_messages(email_data);
+    let total = messages.len();
+    let mut results = Vec::with_capacity(total);
+
+    for (index, message) in messages.into_iter().enumerate() {
+        if cancelled.load(Ordering::SeqCst) {
+            let _ = window.emit(
+                "analysis-error",
+                AnalysisError {
+                    message: "Analysis cancelled".to_string(),
+                },
+            );
+            return;
+        }
+
+        let verdict = match sidecar::analyze(
+            sidecar_state,
+            message.subject.clone(),
+            message.sender.clone(),
+            message.body.clone(),
+        )
+        .await
+        {
+            Ok(response) => response.verdict,
+            Err(err) => {
+                let _ = window.emit("analysis-error", AnalysisError { message: err });
+                return;
+            }
+        };
+        results.push(verdict);
+
+        let _ = window.emit(
+            "analysis-progress",
+            AnalysisProgress {
+                processed: index + 1,
+                total,
+                subject: message.subject,
+                sender: message.sender,
+            },
+        );
+    }
+
+    let _ = window.emit("analysis-complete", AnalysisComplete { results });
+}
+
+/// Requests that the in-flight analysis run stop before its next message.
+#[tauri::command]
+pub fn cancel_analysis(state: State<'_, AnalysisState>) {
+    state.cancelled.store(true, Ordering::SeqCst);
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use tauri::{Manager, RunEvent};
+
+#[cfg(desktop)]
+use tauri::{CustomMenuItem, GlobalShortcutManager, Menu, Submenu};
+
+mod analysis;
+mod mailbox;
+mod sidecar;
+#[cfg(desktop)]
+mod tray;
+mod updater;
+
+use analysis::{analyze_email, cancel_analysis, AnalysisState};
+use mailbox::{load_mailbox, open_mailbox_dialog, LastMailboxState};
+use sidecar::SidecarState;
+use updater::{check_for_update, install_update};
+
+// Learn more about Tauri commands at https://tauri.app/v1/guides/features/command
+#[tauri::command]
+fn greet(name: &str) -> String {
+    format!("Hello, {}! You've been greeted from Rust!", name)
+}
+
+#[cfg(desktop)]
+fn build_menu() -> Menu {
+    let quit = CustomMenuItem::new("quit".to_string(), "Quit");
+    let close = CustomMenuItem::new("close".to_string(), "Close");
+    let file_menu = Submenu::new(
+        "File",
+        Menu::new().add_item(quit).add_item(close),
+    );
+    Menu::new()
+        .add_submenu(file_menu)
+        .add_submenu(updater::help_menu())
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    let sidecar_state = Arc::new(SidecarState::default());
+
+    let mut builder = tauri::Builder::default()
+        .manage(AnalysisState::default())
+        .manage(sidecar_state.clone())
+        .manage(LastMailboxState::default());
+
+    #[cfg(desktop)]
+    {
+        builder = builder
+            .menu(build_menu())
+            .on_menu_event(|event| updater::handle_menu_event(&event))
+            .system_tray(tray::build_tray())
+            .on_system_tray_event(|app, event| tray::handle_tray_event(app, event));
+    }
+
+    let app = builder
+        .setup(move |app| {
+            sidecar::spawn(&app.handle(), sidecar_state.clone())?;
+            let app_handle = app.handle();
+            tauri::async_runtime::spawn(async move {
+                let _ = check_for_update(app_handle).await;
+            });
+
+            #[cfg(desktop)]
+            {
+                let app_handle = app.handle();
+                let registered = app.global_shortcut_manager().register(
+                    "CmdOrCtrl+Shift+Q",
+                    move || {
+                        let app_handle = app_handle.clone();
+                        tauri::async_runtime::spawn(async move {
+                            tray::run_quick_scan(&app_handle).await;
+                        });
+                    },
+                );
+                if let Err(err) = registered {
+                    // Another app may already hold this shortcut; the tray
+                    // menu's "Run Scan" item still works, so don't fail startup.
+                    eprintln!("failed to register quick-scan shortcut: {err}");
+                }
+            }
+
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            analyze_email,
+            cancel_analysis,
+            open_mailbox_dialog,
+            load_mailbox,
+            check_for_update,
+            install_update
+        ])
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application");
+
+    app.run(|app_handle, event| {
+        if let RunEvent::Exit = event {
+            let sidecar_state = app_handle.state::<Arc<SidecarState>>().inner().clone();
+            tauri::async_runtime::block_on(sidecar::shutdown(sidecar_state));
+        }
+    });
+}
@@ -0,0 +1,353 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+#[cfg(desktop)]
+use tauri::api::dialog::blocking::FileDialogBuilder;
+use tauri::State;
+
+/// A decoded MIME attachment, ready to hand to the frontend as base64.
+#[derive(Clone, Serialize)]
+pub struct Attachment {
+    pub filename: String,
+    pub content_type: String,
+    pub data_base64: String,
+}
+
+/// One message pulled from an `.eml` file or an `.mbox` archive, with
+/// headers unfolded and MIME parts walked into plain text plus attachments.
+/// RFC 2047 encoded words in header values (`=?charset?B?...?=`) are kept
+/// as they appear in the message, not decoded.
+#[derive(Clone, Serialize, Default)]
+pub struct ParsedEmail {
+    pub from: String,
+    pub to: String,
+    pub subject: String,
+    pub date: String,
+    pub text_body: String,
+    pub attachments: Vec<Attachment>,
+}
+
+/// Remembers the most recently opened mailbox path so a tray quick-scan can
+/// re-run the same file without the frontend having to pass one in.
+#[derive(Default)]
+pub struct LastMailboxState(Mutex<Option<String>>);
+
+impl LastMailboxState {
+    pub fn get(&self) -> Option<String> {
+        self.0.lock().unwrap().clone()
+    }
+
+    fn set(&self, path: String) {
+        *self.0.lock().unwrap() = Some(path);
+    }
+}
+
+/// Opens a native file-picker restricted to `.eml`/`.mbox` files and returns
+/// the chosen path, or `None` if the user cancelled. The native file dialog
+/// is desktop-only; mobile targets have no picker to open.
+#[cfg(desktop)]
+#[tauri::command]
+pub async fn open_mailbox_dialog() -> Option<String> {
+    tauri::async_runtime::spawn_blocking(|| {
+        FileDialogBuilder::new()
+            .add_filter("Mailbox", &["eml", "mbox"])
+            .pick_file()
+            .map(|path| path.display().to_string())
+    })
+    .await
+    .unwrap_or(None)
+}
+
+#[cfg(not(desktop))]
+#[tauri::command]
+pub async fn open_mailbox_dialog() -> Option<String> {
+    None
+}
+
+/// Reads and parses the mailbox file at `path`. Runs on a blocking thread so
+/// a large `.mbox` archive doesn't stall the webview.
+#[tauri::command]
+pub async fn load_mailbox(
+    path: String,
+    last_mailbox: State<'_, LastMailboxState>,
+) -> Result<Vec<ParsedEmail>, String> {
+    let to_parse = path.clone();
+    let messages = tauri::async_runtime::spawn_blocking(move || load_mailbox_sync(&to_parse))
+        .await
+        .map_err(|e| format!("mailbox parsing task panicked: {e}"))??;
+    last_mailbox.set(path);
+    Ok(messages)
+}
+
+pub(crate) fn load_mailbox_sync(path: &str) -> Result<Vec<ParsedEmail>, String> {
+    let raw = fs::read_to_string(path).map_err(|e| format!("failed to read {path}: {e}"))?;
+    let raw = raw.replace("\r\n", "\n");
+    let is_mbox = PathBuf::from(path)
+        .extension()
+        .map(|ext| ext.eq_ignore_ascii_case("mbox"))
+        .unwrap_or(false);
+
+    let messages: Vec<&str> = if is_mbox {
+        split_mbox(&raw)
+    } else {
+        vec![raw.as_str()]
+    };
+
+    messages.into_iter().map(parse_message).collect()
+}
+
+/// Splits an mbox archive on `From ` separator lines; per the mbox format,
+/// the separator only counts when it starts a line.
+fn split_mbox(raw: &str) -> Vec<&str> {
+    let mut messages = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+    for line in raw.split_inclusive('\n') {
+        if line.starts_with("From ") {
+            if let Some(begin) = start {
+                messages.push(raw[begin..offset].trim_end());
+            }
+            start = Some(offset + line.len());
+        }
+        offset += line.len();
+    }
+    if let Some(begin) = start {
+        messages.push(raw[begin..].trim_end());
+    }
+    messages
+}
+
+fn parse_message(raw: &str) -> Result<ParsedEmail, String> {
+    let (header_block, body) = raw.split_once("\n\n").unwrap_or((raw, ""));
+    let headers = parse_headers(header_block);
+    let content_type = headers.get("content-type").cloned().unwrap_or_default();
+
+    let mut email = ParsedEmail {
+        from: headers.get("from").cloned().unwrap_or_default(),
+        to: headers.get("to").cloned().unwrap_or_default(),
+        subject: headers.get("subject").cloned().unwrap_or_default(),
+        date: headers.get("date").cloned().unwrap_or_default(),
+        ..Default::default()
+    };
+
+    match extract_boundary(&content_type) {
+        Some(boundary) => walk_mime_parts(body, &boundary, &mut email),
+        None => {
+            let encoding = headers
+                .get("content-transfer-encoding")
+                .map(String::as_str)
+                .unwrap_or("7bit");
+            email.text_body = decode_transfer_encoding(body, encoding);
+        }
+    }
+
+    Ok(email)
+}
+
+/// Unfolds RFC 5322 header blocks (continuation lines start with whitespace)
+/// into a lowercase-keyed header map.
+fn parse_headers(block: &str) -> HashMap<String, String> {
+    let mut headers = HashMap::new();
+    let mut current: Option<(String, String)> = None;
+    for line in block.lines() {
+        if line.starts_with(' ') || line.starts_with('\t') {
+            if let Some((_, value)) = current.as_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((name, value)) = current.take() {
+            headers.insert(name.to_ascii_lowercase(), value);
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            current = Some((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    if let Some((name, value)) = current {
+        headers.insert(name.to_ascii_lowercase(), value);
+    }
+    headers
+}
+
+fn extract_boundary(content_type: &str) -> Option<String> {
+    content_type
+        .split(';')
+        .find_map(|part| part.trim().strip_prefix("boundary="))
+        .map(|boundary| boundary.trim_matches('"').to_string())
+}
+
+fn walk_mime_parts(body: &str, boundary: &str, email: &mut ParsedEmail) {
+    let delimiter = format!("--{boundary}");
+    for part in body.split(&delimiter) {
+        let part = part.trim_matches('\n');
+        if part.is_empty() || part == "--" {
+            continue;
+        }
+
+        let (part_headers, part_body) = part.split_once("\n\n").unwrap_or((part, ""));
+        let headers = parse_headers(part_headers);
+        let part_type = headers
+            .get("content-type")
+            .cloned()
+            .unwrap_or_else(|| "text/plain".to_string());
+        let encoding = headers
+            .get("content-transfer-encoding")
+            .map(String::as_str)
+            .unwrap_or("7bit");
+
+        if let Some(nested_boundary) = extract_boundary(&part_type) {
+            walk_mime_parts(part_body, &nested_boundary, email);
+            continue;
+        }
+
+        let disposition = headers
+            .get("content-disposition")
+            .cloned()
+            .unwrap_or_default();
+        if disposition.starts_with("attachment") || part_type.starts_with("application/") {
+            let filename = disposition
+                .split(';')
+                .find_map(|p| p.trim().strip_prefix("filename="))
+                .or_else(|| part_type.split(';').find_map(|p| p.trim().strip_prefix("name=")))
+                .map(|f| f.trim_matches('"').to_string())
+                .unwrap_or_else(|| "attachment".to_string());
+
+            email.attachments.push(Attachment {
+                filename,
+                content_type: part_type,
+                data_base64: reencode_as_base64(part_body, encoding),
+            });
+        } else if part_type.starts_with("text/") {
+            if !email.text_body.is_empty() {
+                email.text_body.push('\n');
+            }
+            email
+                .text_body
+                .push_str(&decode_transfer_encoding(part_body, encoding));
+        }
+    }
+}
+
+fn decode_transfer_encoding(body: &str, encoding: &str) -> String {
+    match encoding.to_ascii_lowercase().as_str() {
+        "quoted-printable" => decode_quoted_printable(body),
+        "base64" => {
+            let stripped: String = body.chars().filter(|c| !c.is_whitespace()).collect();
+            match BASE64.decode(stripped) {
+                Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                Err(_) => String::new(),
+            }
+        }
+        _ => body.to_string(),
+    }
+}
+
+fn reencode_as_base64(body: &str, encoding: &str) -> String {
+    match encoding.to_ascii_lowercase().as_str() {
+        "base64" => body.chars().filter(|c| !c.is_whitespace()).collect(),
+        "quoted-printable" => BASE64.encode(decode_quoted_printable(body).as_bytes()),
+        _ => BASE64.encode(body.as_bytes()),
+    }
+}
+
+fn decode_quoted_printable(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'=' {
+            output.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+        match bytes.get(i + 1..i + 3) {
+            Some(b"\r\n") => i += 3,
+            Some(hex) if bytes[i + 1] != b'\r' && bytes[i + 1] != b'\n' => {
+                if let Ok(byte) = u8::from_str_radix(std::str::from_utf8(hex).unwrap_or(""), 16) {
+                    output.push(byte);
+                }
+                i += 3;
+            }
+            _ => {
+                // lone soft line break (`=\n` or `=\r`) or a trailing `=`
+                i += if matches!(bytes.get(i + 1), Some(b'\n') | Some(b'\r')) { 2 } else { 1 };
+            }
+        }
+    }
+    String::from_utf8_lossy(&output).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_multipart_message_into_text_and_attachment() {
+        let raw = "From: sender@example.com\r\n\
+To: recipient@example.com\r\n\
+Subject: Multipart test\r\n\
+Content-Type: multipart/mixed; boundary=\"BOUNDARY\"\r\n\
+\r\n\
+--BOUNDARY\r\n\
+Content-Type: text/plain\r\n\
+\r\n\
+Hello there.\r\n\
+--BOUNDARY\r\n\
+Content-Type: application/octet-stream\r\n\
+Content-Disposition: attachment; filename=\"note.txt\"\r\n\
+Content-Transfer-Encoding: base64\r\n\
+\r\n\
+aGk=\r\n\
+--BOUNDARY--\r\n";
+
+        let email = parse_message(&raw.replace("\r\n", "\n")).unwrap();
+        assert_eq!(email.from, "sender@example.com");
+        assert_eq!(email.subject, "Multipart test");
+        assert_eq!(email.text_body, "Hello there.");
+        assert_eq!(email.attachments.len(), 1);
+        assert_eq!(email.attachments[0].filename, "note.txt");
+        assert_eq!(email.attachments[0].data_base64, "aGk=");
+    }
+
+    #[test]
+    fn decodes_quoted_printable_body() {
+        let raw = "From: sender@example.com\n\
+Subject: QP test\n\
+Content-Transfer-Encoding: quoted-printable\n\
+\n\
+Caf=C3=A9 au lait=\n and more.";
+
+        let email = parse_message(raw).unwrap();
+        assert_eq!(email.text_body, "Café au lait and more.");
+    }
+
+    #[test]
+    fn splits_mbox_on_leading_from_separators() {
+        let raw = "From sender1@example.com Mon Jan  1 00:00:00 2024\n\
+Subject: First\n\
+\n\
+Body one.\n\
+From sender2@example.com Tue Jan  2 00:00:00 2024\n\
+Subject: Second\n\
+\n\
+Body two.\n";
+
+        let messages = split_mbox(raw);
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].contains("Subject: First"));
+        assert!(messages[1].contains("Subject: Second"));
+
+        let parsed: Vec<ParsedEmail> = messages
+            .into_iter()
+            .map(parse_message)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(parsed[0].text_body, "Body one.");
+        assert_eq!(parsed[1].text_body, "Body two.");
+    }
+}
+
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::api::process::{Command, CommandChild, CommandEvent};
+use tauri::{AppHandle, Manager};
+use tokio::sync::{oneshot, Mutex};
+
+/// One message handed to the Python analyzer over its JSON-RPC-over-stdio
+/// protocol. Serialized as a single newline-delimited JSON line.
+#[derive(Clone, Serialize)]
+pub struct AnalyzeRequest {
+    pub id: u64,
+    pub subject: String,
+    pub sender: String,
+    pub body: String,
+}
+
+/// The sidecar's reply to an `AnalyzeRequest`, matched back up by `id`.
+#[derive(Clone, Deserialize)]
+pub struct AnalyzeResponse {
+    pub id: u64,
+    pub verdict: String,
+    #[serde(default)]
+    pub score: f64,
+}
+
+#[derive(Clone, Serialize)]
+struct SidecarCrashed {
+    message: String,
+}
+
+type PendingReplies = Mutex<HashMap<u64, oneshot::Sender<AnalyzeResponse>>>;
+
+/// Holds the spawned sidecar process and the table of in-flight requests
+/// awaiting a reply, keyed by request id.
+pub struct SidecarState {
+    child: Mutex<Option<CommandChild>>,
+    pending: Arc<PendingReplies>,
+    next_id: AtomicU64,
+}
+
+impl Default for SidecarState {
+    fn default() -> Self {
+        Self {
+            child: Mutex::new(None),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            next_id: AtomicU64::new(1),
+        }
+    }
+}
+
+/// Spawns the bundled `analyzer` sidecar and starts the task that reads its
+/// stdout, matching each reply line back to the request that asked for it.
+/// Called once from the `setup` hook in `run`; a crash or unexpected exit is
+/// surfaced to the frontend as a `sidecar-crashed` event rather than panicking.
+pub fn spawn(app: &AppHandle, state: Arc<SidecarState>) -> Result<(), String> {
+    let (mut rx, child) = Command::new_sidecar("analyzer")
+        .map_err(|e| format!("failed to locate analyzer sidecar: {e}"))?
+        .spawn()
+        .map_err(|e| format!("failed to spawn analyzer sidecar: {e}"))?;
+
+    let pending = state.pending.clone();
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(line) => {
+                    if let Ok(response) = serde_json::from_str::<AnalyzeResponse>(&line) {
+                        if let Some(sender) = pending.lock().await.remove(&response.id) {
+                            let _ = sender.send(response);
+                        }
+                    }
+                }
+                CommandEvent::Stderr(line) => {
+                    eprintln!("analyzer sidecar: {line}");
+                }
+                CommandEvent::Error(message) => {
+                    let _ = app_handle.emit_all("sidecar-crashed", SidecarCrashed { message });
+                }
+                CommandEvent::Terminated(payload) => {
+                    let _ = app_handle.emit_all(
+                        "sidecar-crashed",
+                        SidecarCrashed {
+                            message: format!("analyzer sidecar exited: {:?}", payload.code),
+                        },
+                    );
+                }
+                _ => {}
+            }
+        }
+    });
+
+    *state.child.blocking_lock() = Some(child);
+    Ok(())
+}
+
+/// Sends one message to the sidecar and awaits its matching response,
+/// failing if the sidecar isn't running or doesn't reply within 10 seconds.
+pub async fn analyze(
+    state: &SidecarState,
+    subject: String,
+    sender: String,
+    body: String,
+) -> Result<AnalyzeResponse, String> {
+    let id = state.next_id.fetch_add(1, Ordering::SeqCst);
+    let request = AnalyzeRequest {
+        id,
+        subject,
+        sender,
+        body,
+    };
+
+    let mut line = serde_json::to_string(&request).map_err(|e| e.to_string())?;
+    line.push('\n');
+
+    let (tx, rx) = oneshot::channel();
+    state.pending.lock().await.insert(id, tx);
+
+    let write_result = {
+        let mut child = state.child.lock().await;
+        match child.as_mut() {
+            Some(child) => child
+                .write(line.as_bytes())
+                .map_err(|e| format!("failed to write to analyzer sidecar: {e}")),
+            None => Err("analyzer sidecar is not running".to_string()),
+        }
+    };
+
+    if let Err(err) = write_result {
+        state.pending.lock().await.remove(&id);
+        return Err(err);
+    }
+
+    match tokio::time::timeout(Duration::from_secs(10), rx).await {
+        Ok(Ok(response)) => Ok(response),
+        Ok(Err(_)) => Err("analyzer sidecar closed before replying".to_string()),
+        Err(_) => {
+            state.pending.lock().await.remove(&id);
+            Err("analyzer sidecar timed out".to_string())
+        }
+    }
+}
+
+/// Kills the sidecar process; called when the app exits so no orphaned
+/// Python process is left behind.
+pub async fn shutdown(state: Arc<SidecarState>) {
+    if let Some(child) = state.child.lock().await.take() {
+        let _ = child.kill();
+    }
+}
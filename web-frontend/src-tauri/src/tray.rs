@@ -0,0 +1,121 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tauri::api::notification::Notification;
+use tauri::{
+    AppHandle, CustomMenuItem, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu,
+    SystemTrayMenuItem,
+};
+
+use crate::mailbox::{self, LastMailboxState};
+use crate::sidecar::{self, SidecarState};
+
+/// Guards against the tray menu and the global shortcut starting overlapping
+/// quick scans against the same mailbox.
+static SCAN_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+
+/// Builds the tray menu shown alongside the icon: Open, Run Scan, Quit.
+pub fn build_tray() -> SystemTray {
+    let menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new("open", "Open"))
+        .add_item(CustomMenuItem::new("run_scan", "Run Scan"))
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new("quit", "Quit"));
+    SystemTray::new().with_menu(menu)
+}
+
+/// Routes tray icon clicks and menu selections: left-clicking the icon
+/// toggles the main window, "Open" shows it, "Run Scan" kicks off a quick
+/// scan without requiring the window to be visible, "Quit" exits the app.
+pub fn handle_tray_event(app: &AppHandle, event: SystemTrayEvent) {
+    match event {
+        SystemTrayEvent::LeftClick { .. } => toggle_main_window(app),
+        SystemTrayEvent::MenuItemClick { id, .. } => match id.as_str() {
+            "open" => show_main_window(app),
+            "run_scan" => {
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    run_quick_scan(&app).await;
+                });
+            }
+            "quit" => app.exit(0),
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+fn toggle_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_window("main") {
+        if window.is_visible().unwrap_or(false) {
+            let _ = window.hide();
+        } else {
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+    }
+}
+
+/// Re-scans the last mailbox that was opened and reports the outcome as a
+/// native OS notification, so the tray item and the global shortcut can both
+/// flag a mailbox without bringing the window to the foreground.
+pub async fn run_quick_scan(app: &AppHandle) {
+    if SCAN_IN_PROGRESS.swap(true, Ordering::SeqCst) {
+        notify(app, "Quick scan", "A scan is already in progress");
+        return;
+    }
+
+    run_quick_scan_inner(app).await;
+    SCAN_IN_PROGRESS.store(false, Ordering::SeqCst);
+}
+
+async fn run_quick_scan_inner(app: &AppHandle) {
+    let path = match app.state::<LastMailboxState>().get() {
+        Some(path) => path,
+        None => return notify(app, "Quick scan", "No mailbox has been opened yet"),
+    };
+
+    let messages = match tauri::async_runtime::spawn_blocking(move || {
+        mailbox::load_mailbox_sync(&path)
+    })
+    .await
+    {
+        Ok(Ok(messages)) => messages,
+        Ok(Err(err)) => return notify(app, "Quick scan failed", &err),
+        Err(err) => return notify(app, "Quick scan failed", &err.to_string()),
+    };
+
+    let sidecar_state = app.state::<Arc<SidecarState>>().inner().clone();
+    let mut flagged = 0;
+    for message in &messages {
+        let verdict = sidecar::analyze(
+            &sidecar_state,
+            message.subject.clone(),
+            message.from.clone(),
+            message.text_body.clone(),
+        )
+        .await;
+        if matches!(verdict, Ok(response) if response.verdict != "clean") {
+            flagged += 1;
+        }
+    }
+
+    notify(
+        app,
+        "Quick scan complete",
+        &format!("{flagged} suspicious message(s) out of {}", messages.len()),
+    );
+}
+
+fn notify(app: &AppHandle, title: &str, body: &str) {
+    let _ = Notification::new(&app.config().tauri.bundle.identifier)
+        .title(title)
+        .body(body)
+        .show();
+}
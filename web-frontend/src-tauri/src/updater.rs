@@ -0,0 +1,92 @@
+use serde::Serialize;
+use tauri::{AppHandle, CustomMenuItem, Manager, Menu, Submenu, WindowMenuEvent};
+
+#[derive(Clone, Serialize)]
+struct UpdateAvailable {
+    version: String,
+    notes: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+struct UpdateProgress {
+    status: &'static str,
+}
+
+#[derive(Clone, Serialize)]
+struct UpdateInstalled;
+
+#[derive(Clone, Serialize)]
+struct UpdateCheckFailed {
+    message: String,
+}
+
+/// The "Help" menu carrying the manual update-check entry, added alongside
+/// the existing `File` menu in `build_menu`.
+pub fn help_menu() -> Submenu {
+    Submenu::new(
+        "Help",
+        Menu::new().add_item(CustomMenuItem::new("check_for_updates", "Check for Updates")),
+    )
+}
+
+/// Dispatches the "Check for Updates" menu item to the same check the
+/// frontend can trigger via the `check_for_update` command.
+pub fn handle_menu_event(event: &WindowMenuEvent) {
+    if event.menu_item_id() == "check_for_updates" {
+        let app = event.window().app_handle();
+        tauri::async_runtime::spawn(async move {
+            let _ = check_for_update(app).await;
+        });
+    }
+}
+
+/// Checks the configured release endpoint for a newer signed build and, if
+/// one exists, emits `update-available` for the frontend to render. Network
+/// failures (e.g. offline) are reported via `update-check-failed` rather than
+/// surfaced as a command error, so a missed check never blocks the app.
+#[tauri::command]
+pub async fn check_for_update(app: AppHandle) -> Result<bool, String> {
+    match app.updater().check().await {
+        Ok(update) if update.is_update_available() => {
+            let _ = app.emit_all(
+                "update-available",
+                UpdateAvailable {
+                    version: update.latest_version().to_string(),
+                    notes: update.body().map(str::to_string),
+                },
+            );
+            Ok(true)
+        }
+        Ok(_) => Ok(false),
+        Err(e) => {
+            let _ = app.emit_all(
+                "update-check-failed",
+                UpdateCheckFailed {
+                    message: e.to_string(),
+                },
+            );
+            Ok(false)
+        }
+    }
+}
+
+/// Downloads and installs the pending update, verifying its signature (the
+/// updater refuses to install anything that doesn't match the configured
+/// public key) and emits `update-progress`/`update-installed` so the
+/// frontend can prompt the user to restart.
+#[tauri::command]
+pub async fn install_update(app: AppHandle) -> Result<(), String> {
+    let update = app.updater().check().await.map_err(|e| e.to_string())?;
+    if !update.is_update_available() {
+        return Err("no update available".to_string());
+    }
+
+    let _ = app.emit_all("update-progress", UpdateProgress { status: "downloading" });
+    update
+        .download_and_install()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let _ = app.emit_all("update-installed", UpdateInstalled);
+    Ok(())
+}